@@ -0,0 +1,157 @@
+use std::rc::Rc;
+
+use rand::prelude::*;
+
+use crate::decoder::{decode_chromosome, relaxed_violation};
+use crate::genome::{Generate, Genome, MultiObjective};
+use crate::graph::Graph;
+
+// Contexto compartido por todos los cromosomas de una misma ejecución: el
+// grafo sobre el que se decodifica y los límites de recursos. Se envuelve
+// en `Rc` para que cada cromosoma pueda llevar su propia referencia barata
+// de clonar y seguir implementando `Clone`.
+pub struct DecodeContext {
+    pub graph: Graph,
+    pub resource_limits: Vec<f64>,
+    // Peso de la penalización aplicada a individuos infactibles (ver
+    // `RcsppChromosome::evaluate`).
+    pub penalty_weight: f64,
+}
+
+// Penalización usada cuando ni siquiera ignorando los límites de recursos
+// existe un camino estructural hasta el sumidero (ver
+// `decoder::relaxed_violation`). Es el último recurso: siempre que haya
+// algún camino, aunque exceda los recursos, se penaliza proporcionalmente
+// a cuánto los excede en vez de aplicar esta constante.
+pub(crate) const UNREACHABLE_PENALTY: f64 = 1_000.0;
+
+// Parámetros necesarios para generar un `RcsppChromosome` aleatorio.
+pub struct PermutationParams {
+    pub context: Rc<DecodeContext>,
+}
+
+// Cromosoma de permutación: una permutación de los nodos intermedios
+// (1 a n-2) que el decodificador usa como prioridad de expansión.
+#[derive(Clone)]
+pub struct RcsppChromosome {
+    pub genes: Vec<usize>,
+    fitness: f64,
+    // Objetivos para el modo multiobjetivo: [costo, recurso_0, recurso_1, ...].
+    // Un individuo infactible recibe objetivos en +infinito para quedar
+    // dominado por cualquier solución factible.
+    objectives: Vec<f64>,
+    context: Rc<DecodeContext>,
+}
+
+impl RcsppChromosome {
+    // Todo camino que el decodificador encuentra ya es factible. Cuando no
+    // encuentra ninguno, no tratamos a todo infactible por igual: pedimos
+    // una segunda estimación relajada (que sí excede los límites) para
+    // penalizar proporcionalmente a cuánto se excedieron los recursos, y
+    // sólo caemos a la constante plana si ni siquiera así hay camino.
+    fn evaluate(&mut self) {
+        match decode_chromosome(&self.context.graph, &self.genes, &self.context.resource_limits) {
+            Some(result) => {
+                self.fitness = 1.0 / result.cost;
+                self.objectives = std::iter::once(result.cost).chain(result.resources).collect();
+            }
+            None => {
+                let violation = relaxed_violation(&self.context.graph, &self.genes, &self.context.resource_limits)
+                    .unwrap_or(UNREACHABLE_PENALTY);
+                self.fitness = -(self.context.penalty_weight * violation);
+                self.objectives = vec![f64::INFINITY; 1 + self.context.resource_limits.len()];
+            }
+        }
+    }
+
+    pub fn path(&self) -> Option<(Vec<usize>, f64)> {
+        decode_chromosome(&self.context.graph, &self.genes, &self.context.resource_limits)
+            .map(|result| (result.path, result.cost))
+    }
+}
+
+impl Generate for RcsppChromosome {
+    type Params = PermutationParams;
+
+    // Genera un cromosoma aleatorio (permutación de nodos intermedios)
+    fn generate(params: &Self::Params, rng: &mut impl Rng) -> Self {
+        let mut genes: Vec<usize> = (1..params.context.graph.num_nodes - 1).collect();
+        genes.shuffle(rng);
+
+        let mut chromosome = RcsppChromosome {
+            genes,
+            fitness: 0.0,
+            objectives: Vec::new(),
+            context: Rc::clone(&params.context),
+        };
+        chromosome.evaluate();
+        chromosome
+    }
+}
+
+impl Genome for RcsppChromosome {
+    // Cruce de orden (Order Crossover - OX)
+    fn crossover(&self, other: &Self, rng: &mut impl Rng) -> Self {
+        let n = self.genes.len();
+        let point1 = rng.random_range(0..n);
+        let point2 = rng.random_range(0..n);
+
+        let (start, end) = if point1 < point2 { (point1, point2) } else { (point2, point1) };
+
+        // Inicializamos el hijo con marcadores
+        let mut child_genes = vec![0; n];
+        let mut used = vec![false; n + 2]; // los genes van de 1 a n (n = nodos intermedios)
+
+        // Copiamos el segmento de self
+        for i in start..=end {
+            child_genes[i] = self.genes[i];
+            used[self.genes[i]] = true;
+        }
+
+        // Rellenamos con elementos de other en orden
+        let mut j = (end + 1) % n;
+        let mut other_idx = 0;
+
+        while other_idx < n {
+            let gene = other.genes[other_idx];
+            if !used[gene] {
+                child_genes[j] = gene;
+                j = (j + 1) % n;
+                if j == start {
+                    break;
+                }
+            }
+            other_idx += 1;
+        }
+
+        let mut child = RcsppChromosome {
+            genes: child_genes,
+            fitness: 0.0,
+            objectives: Vec::new(),
+            context: Rc::clone(&self.context),
+        };
+        child.evaluate();
+        child
+    }
+
+    // Mutación (intercambio de dos posiciones aleatorias)
+    fn mutate(&mut self, mutation_rate: f64, rng: &mut impl Rng) {
+        if rng.random::<f64>() < mutation_rate {
+            let n = self.genes.len();
+            let i = rng.random_range(0..n);
+            let j = rng.random_range(0..n);
+            self.genes.swap(i, j);
+            self.evaluate();
+        }
+    }
+
+    fn fitness(&self) -> f64 {
+        self.fitness
+    }
+}
+
+impl MultiObjective for RcsppChromosome {
+    fn objectives(&self) -> &[f64] {
+        &self.objectives
+    }
+}
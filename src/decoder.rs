@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::graph::Graph;
+
+// Una etiqueta (label) representa un camino parcial hasta `node` con un
+// costo y un vector de recursos consumidos dados, más el índice de su
+// etiqueta padre en `labels` (para reconstruir el camino al final).
+#[derive(Clone)]
+struct Label {
+    node: usize,
+    cost: f64,
+    resources: Vec<f64>,
+    priority: usize, // Basado en la permutación del cromosoma
+    parent: Option<usize>,
+}
+
+// A domina a B si A es <= B en costo y en todos los recursos, y
+// estrictamente mejor en al menos uno de ellos.
+fn dominates(a: &Label, b: &Label) -> bool {
+    let not_worse = a.cost <= b.cost && a.resources.iter().zip(&b.resources).all(|(x, y)| x <= y);
+    let strictly_better = a.cost < b.cost || a.resources.iter().zip(&b.resources).any(|(x, y)| x < y);
+    not_worse && strictly_better
+}
+
+// A es al menos tan buena como B (domina o empata) en costo y en todos
+// los recursos. A diferencia de `dominates`, dos etiquetas idénticas se
+// consideran mutuamente "al menos tan buenas", lo que usamos para
+// rechazar duplicados exactos al insertar: sin este chequeo, un ciclo que
+// no empeora ningún objetivo (p. ej. una arista de costo 0 y recursos 0)
+// generaría una etiqueta nueva e indistinguible en cada vuelta, y ni
+// `dominates` ni la cola de prioridad terminarían nunca de crecer.
+fn at_least_as_good(a: &Label, b: &Label) -> bool {
+    a.cost <= b.cost && a.resources.iter().zip(&b.resources).all(|(x, y)| x <= y)
+}
+
+// Entrada de la cola de prioridad: mantiene el mismo orden que el A*
+// original (primero por prioridad de la permutación, luego por costo),
+// pero ya no se usa para cerrar nodos, sólo para decidir qué etiqueta
+// expandir primero.
+struct QueueEntry {
+    priority: usize,
+    cost: f64,
+    label_id: usize,
+}
+
+impl PartialEq for QueueEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.cost == other.cost
+    }
+}
+
+impl Eq for QueueEntry {}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+            .then_with(|| other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal))
+    }
+}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Resultado de decodificar un cromosoma: el camino mínimo-costo
+// encontrado y su costo. El decodificador sólo genera etiquetas
+// factibles (ver más abajo), así que a diferencia de versiones previas ya
+// no hace falta reportar cuánto se excedieron los recursos: o hay un
+// camino factible, o no lo hay.
+pub struct DecodeResult {
+    pub path: Vec<usize>,
+    pub cost: f64,
+    pub resources: Vec<f64>,
+}
+
+// Decodifica una permutación de nodos mediante label-correcting con
+// dominancia de recursos, en lugar de un A* de una sola etiqueta por
+// nodo. Un `HashSet<node>` de visitados (como en versiones anteriores) es
+// incorrecto para un shortest path con restricciones de recursos: la
+// primera vez que se extrae un nodo de la cola puede no ser vía el camino
+// que, más caro, es el único capaz de llegar al sumidero dentro de los
+// límites. En vez de cerrar nodos, mantenemos por nodo la lista de
+// etiquetas no dominadas: al extender una arista se descarta la nueva
+// etiqueta si excede algún límite de recursos, y si ninguna de las ya
+// presentes en el nodo destino es al menos tan buena como ella, se
+// inserta en esa lista podando a su vez las etiquetas que ella misma
+// domina. La permutación
+// del cromosoma sigue usándose como prioridad de expansión (desempate),
+// pero ya no determina qué nodos quedan cerrados. Al final se devuelve la
+// etiqueta de menor costo entre las que alcanzan `n-1`.
+//
+// Se asume que los costos y recursos de las aristas no son negativos: un
+// ciclo que reduzca el costo o algún recurso indefinidamente haría que
+// las etiquetas mejoraran para siempre y la búsqueda nunca terminaría,
+// igual que un Dijkstra clásico con aristas negativas.
+pub fn decode_chromosome(
+    graph: &Graph,
+    genes: &[usize],
+    resource_limits: &[f64],
+) -> Option<DecodeResult> {
+    let mut priorities = HashMap::new();
+    for (i, &node) in genes.iter().enumerate() {
+        priorities.insert(node, i);
+    }
+
+    let mut labels: Vec<Label> = Vec::new();
+    let mut labels_by_node: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    let root = Label {
+        node: 0,
+        cost: 0.0,
+        resources: vec![0.0; resource_limits.len()],
+        priority: 0,
+        parent: None,
+    };
+    labels.push(root);
+    labels_by_node.entry(0).or_default().push(0);
+
+    let mut queue = BinaryHeap::new();
+    queue.push(QueueEntry { priority: 0, cost: 0.0, label_id: 0 });
+
+    let mut best_sink: Option<usize> = None;
+
+    while let Some(entry) = queue.pop() {
+        let label_id = entry.label_id;
+
+        // La etiqueta pudo haber sido dominada y podada después de
+        // encolarla; si ya no sigue viva en la lista de su nodo, la
+        // descartamos en lugar de expandirla.
+        let node = labels[label_id].node;
+        if !labels_by_node.get(&node).is_some_and(|ids| ids.contains(&label_id)) {
+            continue;
+        }
+
+        if node == graph.num_nodes - 1 {
+            if best_sink.is_none_or(|best_id| labels[label_id].cost < labels[best_id].cost) {
+                best_sink = Some(label_id);
+            }
+            continue;
+        }
+
+        let Some(edges) = graph.edges.get(&node) else { continue };
+
+        for edge in edges {
+            let current = &labels[label_id];
+            let mut new_resources = current.resources.clone();
+            let mut feasible = true;
+            for i in 0..resource_limits.len() {
+                new_resources[i] += edge.resources[i];
+                if new_resources[i] > resource_limits[i] {
+                    feasible = false;
+                    break;
+                }
+            }
+            if !feasible {
+                continue;
+            }
+
+            let new_label = Label {
+                node: edge.to,
+                cost: current.cost + edge.cost,
+                resources: new_resources,
+                priority: *priorities.get(&edge.to).unwrap_or(&usize::MAX),
+                parent: Some(label_id),
+            };
+
+            let existing = labels_by_node.entry(edge.to).or_default();
+            if existing.iter().any(|&id| at_least_as_good(&labels[id], &new_label)) {
+                continue;
+            }
+            existing.retain(|&id| !dominates(&new_label, &labels[id]));
+
+            let new_id = labels.len();
+            let priority = new_label.priority;
+            let cost = new_label.cost;
+            labels.push(new_label);
+            labels_by_node.get_mut(&edge.to).unwrap().push(new_id);
+            queue.push(QueueEntry { priority, cost, label_id: new_id });
+        }
+    }
+
+    best_sink.map(|id| {
+        let mut path = vec![labels[id].node];
+        let mut current = &labels[id];
+        while let Some(parent_id) = current.parent {
+            path.push(labels[parent_id].node);
+            current = &labels[parent_id];
+        }
+        path.reverse();
+        DecodeResult { path, cost: labels[id].cost, resources: labels[id].resources.clone() }
+    })
+}
+
+// Estado de la búsqueda relajada: igual que `Label`, pero sin lista de
+// padres porque aquí sólo nos importa el vector de recursos al llegar al
+// sumidero, no reconstruir el camino.
+struct RelaxedState {
+    node: usize,
+    cost: f64,
+    resources: Vec<f64>,
+    priority: usize,
+}
+
+impl PartialEq for RelaxedState {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.cost == other.cost
+    }
+}
+
+impl Eq for RelaxedState {}
+
+impl Ord for RelaxedState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+            .then_with(|| other.cost.partial_cmp(&self.cost).unwrap_or(Ordering::Equal))
+    }
+}
+
+impl PartialOrd for RelaxedState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Cuando `decode_chromosome` no encuentra ningún camino factible, esta
+// búsqueda auxiliar estima qué tan lejos estuvo de serlo: es el A* de una
+// sola etiqueta por nodo que usaba el decodificador antes de
+// label-correcting, pero sin descartar aristas que excedan los límites de
+// recursos, acumulando en su lugar cuánto se excede. Devuelve
+// `Σ max(0, usado[i] - límite[i])` del camino de menor costo hasta el
+// sumidero según la prioridad de la permutación, o `None` si ni siquiera
+// ignorando los límites existe un camino estructural hasta `n-1`. No
+// necesita ser exacta (no explora dominancia de recursos): sólo alimenta
+// la penalización de `RcsppChromosome::evaluate` con una señal de
+// distancia a factibilidad en vez de una constante plana para todo
+// infactible por igual.
+pub fn relaxed_violation(graph: &Graph, genes: &[usize], resource_limits: &[f64]) -> Option<f64> {
+    let mut priorities = HashMap::new();
+    for (i, &node) in genes.iter().enumerate() {
+        priorities.insert(node, i);
+    }
+
+    let mut queue = BinaryHeap::new();
+    let mut visited = std::collections::HashSet::new();
+
+    queue.push(RelaxedState {
+        node: 0,
+        cost: 0.0,
+        resources: vec![0.0; resource_limits.len()],
+        priority: 0,
+    });
+
+    while let Some(current) = queue.pop() {
+        if current.node == graph.num_nodes - 1 {
+            let violation = current
+                .resources
+                .iter()
+                .zip(resource_limits)
+                .map(|(used, limit)| (used - limit).max(0.0))
+                .sum();
+            return Some(violation);
+        }
+
+        if visited.contains(&current.node) {
+            continue;
+        }
+        visited.insert(current.node);
+
+        if let Some(edges) = graph.edges.get(&current.node) {
+            for edge in edges {
+                let mut new_resources = current.resources.clone();
+                for (used, consumed) in new_resources.iter_mut().zip(&edge.resources) {
+                    *used += consumed;
+                }
+
+                queue.push(RelaxedState {
+                    node: edge.to,
+                    cost: current.cost + edge.cost,
+                    resources: new_resources,
+                    priority: *priorities.get(&edge.to).unwrap_or(&usize::MAX),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_edge(graph: &mut Graph, from: usize, to: usize, cost: f64, resources: Vec<f64>) {
+        graph.edges.entry(from).or_default().push(crate::graph::Edge { to, cost, resources });
+    }
+
+    // Dos caminos distintos llegan al nodo 2: uno barato pero que ya
+    // gastó casi todo el límite de recursos (0->1->2), y otro más caro
+    // pero liviano en recursos (0->2 directo). Sólo el segundo puede
+    // pagar la arista final 2->3 sin exceder el límite. Un decodificador
+    // que cierre nodos por costo mínimo (A* de una sola etiqueta) se
+    // quedaría solo con el camino barato, lo descartaría por infactible
+    // al llegar al sumidero, y fallaría en encontrar el camino factible
+    // de costo 7 que sí existe.
+    #[test]
+    fn keeps_costlier_resource_lighter_label_alive() {
+        let mut graph = Graph { num_nodes: 4, edges: HashMap::new() };
+        push_edge(&mut graph, 0, 1, 1.0, vec![4.0]);
+        push_edge(&mut graph, 1, 2, 1.0, vec![0.0]);
+        push_edge(&mut graph, 0, 2, 5.0, vec![0.0]);
+        push_edge(&mut graph, 2, 3, 2.0, vec![2.0]);
+
+        let result = decode_chromosome(&graph, &[1, 2], &[5.0]).expect("debe existir un camino factible");
+
+        assert_eq!(result.path, vec![0, 2, 3]);
+        assert_eq!(result.cost, 7.0);
+    }
+
+    // Ciclo de costo y recursos cero entre los nodos 1 y 2: ninguna
+    // etiqueta nueva generada al recorrerlo empeora ningún objetivo frente
+    // a la que ya está en el nodo, así que `at_least_as_good` debe
+    // rechazarla y permitir que la búsqueda termine en vez de encolar
+    // etiquetas indistinguibles para siempre.
+    #[test]
+    fn terminates_on_zero_cost_cycle() {
+        let mut graph = Graph { num_nodes: 4, edges: HashMap::new() };
+        push_edge(&mut graph, 0, 1, 1.0, vec![1.0]);
+        push_edge(&mut graph, 1, 2, 0.0, vec![0.0]);
+        push_edge(&mut graph, 2, 1, 0.0, vec![0.0]);
+        push_edge(&mut graph, 1, 3, 1.0, vec![1.0]);
+
+        let result = decode_chromosome(&graph, &[1, 2], &[10.0]).expect("debe existir un camino factible");
+
+        assert_eq!(result.path, vec![0, 1, 3]);
+        assert_eq!(result.cost, 2.0);
+    }
+}
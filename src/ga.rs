@@ -0,0 +1,64 @@
+use rand::Rng;
+
+use crate::genome::{Generate, Genome};
+use crate::selection::{select, Selection};
+
+// Parámetros que gobiernan la evolución, independientes de la
+// codificación del individuo.
+pub struct GaConfig {
+    pub population_size: usize,
+    pub generations: usize,
+    pub crossover_rate: f64,
+    pub mutation_rate: f64,
+    pub selection: Selection,
+}
+
+// Motor genético genérico: opera sobre cualquier codificación que
+// implemente `Genome` + `Generate`, para no tener que copiar el bucle de
+// población, el elitismo y la selección cada vez que se añade una nueva
+// codificación (permutación, vector binario, vector real, ...).
+pub fn genetic_algorithm<G: Genome + Generate>(params: &G::Params, config: &GaConfig) -> G {
+    let mut rng = rand::rng();
+
+    // Generamos población inicial
+    let mut population: Vec<G> = (0..config.population_size)
+        .map(|_| G::generate(params, &mut rng))
+        .collect();
+
+    for _ in 0..config.generations {
+        // Ordenamos por fitness (descendente)
+        population.sort_by(|a, b| b.fitness().partial_cmp(&a.fitness()).unwrap());
+
+        // Aplicamos elitismo (conservamos los mejores)
+        let elite_size = (config.population_size as f64 * 0.1) as usize;
+        let elite = population.iter().take(elite_size).cloned().collect::<Vec<_>>();
+
+        let mut new_population = elite;
+
+        // Generamos nueva población
+        while new_population.len() < config.population_size {
+            let parent1 = select(&population, &config.selection, &mut rng);
+            let parent2 = select(&population, &config.selection, &mut rng);
+
+            // Aplicamos cruce con cierta probabilidad
+            let mut child = if rng.random::<f64>() < config.crossover_rate {
+                parent1.crossover(&parent2, &mut rng)
+            } else if parent1.fitness() > parent2.fitness() {
+                parent1.clone()
+            } else {
+                parent2.clone()
+            };
+
+            // Aplicamos mutación
+            child.mutate(config.mutation_rate, &mut rng);
+
+            new_population.push(child);
+        }
+
+        population = new_population;
+    }
+
+    // Ordenamos población final y devolvemos el mejor individuo
+    population.sort_by(|a, b| b.fitness().partial_cmp(&a.fitness()).unwrap());
+    population.into_iter().next().unwrap()
+}
@@ -0,0 +1,36 @@
+use rand::Rng;
+
+// Trait que debe implementar cualquier codificación de individuo para que
+// el motor genético genérico (ver `ga.rs`) pueda operar sobre ella sin
+// conocer los detalles concretos (permutación, vector real, etc). La
+// codificación de permutación de `chromosome.rs` + decodificador
+// label-correcting de `decoder.rs` es solo una implementación posible.
+pub trait Genome: Clone {
+    // Combina `self` con `other` para producir un descendiente.
+    fn crossover(&self, other: &Self, rng: &mut impl Rng) -> Self;
+
+    // Perturba el genoma in-place según una tasa/intensidad de mutación.
+    fn mutate(&mut self, rate: f64, rng: &mut impl Rng);
+
+    // Fitness ya evaluado del individuo (mayor es mejor).
+    fn fitness(&self) -> f64;
+}
+
+// Hook de inicialización aleatoria, separado de `Genome` porque requiere
+// un contexto externo (el grafo, los límites de recursos, ...) que no
+// tiene sentido exigirle al propio genoma una vez ya construido.
+pub trait Generate: Sized {
+    type Params;
+
+    fn generate(params: &Self::Params, rng: &mut impl Rng) -> Self;
+}
+
+// Extensión de `Genome` para individuos que se evalúan según varios
+// objetivos simultáneos (costo, consumo de cada recurso, ...) en lugar de
+// un único escalar de fitness. `genetic_algorithm_pareto` (ver
+// `pareto.rs`) opera sobre este trait en vez de `Genome::fitness` para
+// evolucionar un frente de Pareto. Se asume minimización en todos los
+// objetivos.
+pub trait MultiObjective: Genome {
+    fn objectives(&self) -> &[f64];
+}
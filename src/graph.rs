@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+// Estructura para representar el grafo
+pub struct Graph {
+    pub num_nodes: usize,
+    pub edges: HashMap<usize, Vec<Edge>>,
+}
+
+pub struct Edge {
+    pub to: usize,
+    pub cost: f64,
+    pub resources: Vec<f64>, // Vector de recursos consumidos
+}
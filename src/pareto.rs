@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::ga::GaConfig;
+use crate::genome::{Generate, MultiObjective};
+
+// p domina a q si p es <= q en todos los objetivos y < en al menos uno.
+// Se asume minimización en todos los objetivos (costo, consumo de cada
+// recurso, ...).
+fn dominates(p: &[f64], q: &[f64]) -> bool {
+    let not_worse = p.iter().zip(q).all(|(a, b)| a <= b);
+    let strictly_better = p.iter().zip(q).any(|(a, b)| a < b);
+    not_worse && strictly_better
+}
+
+// Particiona la población en frentes de no-dominancia al estilo NSGA-II.
+// Devuelve, para cada individuo (por índice), el rango de su frente (0 =
+// frente de Pareto).
+fn fast_non_dominated_sort(objectives: &[Vec<f64>]) -> Vec<usize> {
+    let n = objectives.len();
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut domination_count = vec![0usize; n];
+    let mut ranks = vec![0usize; n];
+    let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+
+    for p in 0..n {
+        for q in 0..n {
+            if p == q {
+                continue;
+            }
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated_by[p].push(q);
+            } else if dominates(&objectives[q], &objectives[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            fronts[0].push(p);
+        }
+    }
+
+    let mut i = 0;
+    while !fronts[i].is_empty() {
+        let mut next_front = Vec::new();
+        for &p in &fronts[i] {
+            for &q in &dominated_by[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    ranks[q] = i + 1;
+                    next_front.push(q);
+                }
+            }
+        }
+        i += 1;
+        fronts.push(next_front);
+    }
+
+    ranks
+}
+
+// Distancia de crowding dentro de un mismo frente: para cada objetivo se
+// ordena el frente y se suma, por individuo, el hueco normalizado con sus
+// vecinos; los puntos extremos de cada objetivo reciben distancia
+// infinita para preservar diversidad en los bordes del frente.
+fn crowding_distance(front: &[usize], objectives: &[Vec<f64>]) -> HashMap<usize, f64> {
+    let mut distance: HashMap<usize, f64> = front.iter().map(|&i| (i, 0.0)).collect();
+    if front.len() <= 2 {
+        for &i in front {
+            distance.insert(i, f64::INFINITY);
+        }
+        return distance;
+    }
+
+    let num_objectives = objectives[front[0]].len();
+    // `m` indexa el objetivo dentro de cada vector, no `objectives` en sí.
+    #[allow(clippy::needless_range_loop)]
+    for m in 0..num_objectives {
+        let mut sorted = front.to_vec();
+        sorted.sort_by(|&a, &b| objectives[a][m].partial_cmp(&objectives[b][m]).unwrap());
+
+        let min = objectives[sorted[0]][m];
+        let max = objectives[sorted[sorted.len() - 1]][m];
+        let range = max - min;
+
+        distance.insert(sorted[0], f64::INFINITY);
+        distance.insert(sorted[sorted.len() - 1], f64::INFINITY);
+
+        // `range <= 0.0` no basta: si todo el frente es infactible, `min`
+        // y `max` son ambos +INFINITY (ver `RcsppChromosome::evaluate`) y
+        // `range` da NaN, que no es `<= 0.0` pero tampoco sirve para
+        // normalizar nada.
+        if range.is_nan() || range <= 0.0 {
+            continue;
+        }
+
+        for w in 1..sorted.len() - 1 {
+            let prev = objectives[sorted[w - 1]][m];
+            let next = objectives[sorted[w + 1]][m];
+            let entry = distance.entry(sorted[w]).or_insert(0.0);
+            if entry.is_finite() {
+                *entry += (next - prev) / range;
+            }
+        }
+    }
+
+    distance
+}
+
+// Torneo binario de NSGA-II: compara dos individuos por (rango asc,
+// crowding desc) en vez de por un único escalar de fitness.
+fn nsga2_tournament<G: Clone>(
+    population: &[G],
+    ranks: &[usize],
+    crowding: &HashMap<usize, f64>,
+    rng: &mut impl Rng,
+) -> G {
+    let a = rng.random_range(0..population.len());
+    let b = rng.random_range(0..population.len());
+
+    let a_wins = match ranks[a].cmp(&ranks[b]) {
+        Ordering::Less => true,
+        Ordering::Greater => false,
+        Ordering::Equal => crowding[&a] >= crowding[&b],
+    };
+
+    if a_wins { population[a].clone() } else { population[b].clone() }
+}
+
+// Variante multiobjetivo del motor genético: en vez de colapsar costo y
+// recursos en un único fitness escalar, trata cada uno como un objetivo
+// separado y evoluciona un frente de Pareto completo. Reutiliza
+// `GaConfig` para los parámetros que no dependen de la estrategia de
+// selección (la selección aquí siempre es el torneo binario de NSGA-II,
+// guiado por rango y crowding en vez de `config.selection`).
+pub fn genetic_algorithm_pareto<G: Generate + MultiObjective>(
+    params: &G::Params,
+    config: &GaConfig,
+) -> Vec<G> {
+    let mut rng = rand::rng();
+
+    let mut population: Vec<G> = (0..config.population_size)
+        .map(|_| G::generate(params, &mut rng))
+        .collect();
+
+    for _ in 0..config.generations {
+        let objectives: Vec<Vec<f64>> = population.iter().map(|g| g.objectives().to_vec()).collect();
+        let ranks = fast_non_dominated_sort(&objectives);
+
+        let mut fronts: Vec<Vec<usize>> = Vec::new();
+        for (idx, &rank) in ranks.iter().enumerate() {
+            if fronts.len() <= rank {
+                fronts.resize(rank + 1, Vec::new());
+            }
+            fronts[rank].push(idx);
+        }
+
+        let mut crowding = HashMap::new();
+        for front in &fronts {
+            crowding.extend(crowding_distance(front, &objectives));
+        }
+
+        // Elitismo: igual que en `genetic_algorithm`, pero ordenando por
+        // (rango asc, crowding desc) en vez de por fitness.
+        let mut order: Vec<usize> = (0..population.len()).collect();
+        order.sort_by(|&a, &b| {
+            ranks[a]
+                .cmp(&ranks[b])
+                .then_with(|| crowding[&b].partial_cmp(&crowding[&a]).unwrap_or(Ordering::Equal))
+        });
+
+        let elite_size = (config.population_size as f64 * 0.1) as usize;
+        let mut new_population: Vec<G> =
+            order.iter().take(elite_size).map(|&i| population[i].clone()).collect();
+
+        while new_population.len() < config.population_size {
+            let parent1 = nsga2_tournament(&population, &ranks, &crowding, &mut rng);
+            let parent2 = nsga2_tournament(&population, &ranks, &crowding, &mut rng);
+
+            let mut child = if rng.random::<f64>() < config.crossover_rate {
+                parent1.crossover(&parent2, &mut rng)
+            } else {
+                parent1.clone()
+            };
+
+            child.mutate(config.mutation_rate, &mut rng);
+            new_population.push(child);
+        }
+
+        population = new_population;
+    }
+
+    // Devolvemos el frente de Pareto final (rango 0) en vez de un único
+    // mejor individuo.
+    let objectives: Vec<Vec<f64>> = population.iter().map(|g| g.objectives().to_vec()).collect();
+    let ranks = fast_non_dominated_sort(&objectives);
+
+    population
+        .into_iter()
+        .zip(ranks)
+        .filter(|&(_, rank)| rank == 0)
+        .map(|(genome, _)| genome)
+        .collect()
+}
@@ -0,0 +1,141 @@
+use std::rc::Rc;
+
+use rand::prelude::*;
+
+use crate::chromosome::{DecodeContext, UNREACHABLE_PENALTY};
+use crate::decoder::{decode_chromosome, relaxed_violation};
+use crate::genome::{Generate, Genome};
+
+// Parámetros necesarios para generar un `PriorityKeyChromosome` aleatorio.
+pub struct PriorityKeyParams {
+    pub context: Rc<DecodeContext>,
+    // Desviación estándar inicial del ruido gaussiano de mutación.
+    pub initial_sigma: f64,
+    // Factor multiplicativo aplicado a `sigma` tras cada mutación, para
+    // que el ruido vaya decayendo de exploración a explotación fina a lo
+    // largo de las generaciones. Usar 1.0 desactiva el recocido.
+    pub sigma_decay: f64,
+}
+
+// Codificación alternativa al cromosoma de permutación: en vez de una
+// permutación dura de los nodos intermedios, cada uno recibe una clave de
+// prioridad real; el orden de visita se deriva ordenando esas claves. Al
+// ser valores continuos admiten cruce aritmético (blend) y perturbaciones
+// gaussianas pequeñas, algo que una permutación no puede expresar sin
+// recurrir a intercambios discretos.
+#[derive(Clone)]
+pub struct PriorityKeyChromosome {
+    // Una clave por nodo intermedio (nodo 1 + i tiene clave `keys[i]`).
+    pub keys: Vec<f64>,
+    fitness: f64,
+    sigma: f64,
+    sigma_decay: f64,
+    context: Rc<DecodeContext>,
+}
+
+impl PriorityKeyChromosome {
+    // Deriva el orden de visita ordenando los nodos intermedios por su
+    // clave de prioridad.
+    fn genes(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (1..self.context.graph.num_nodes - 1).collect();
+        order.sort_by(|&a, &b| self.keys[a - 1].partial_cmp(&self.keys[b - 1]).unwrap());
+        order
+    }
+
+    // Igual que `RcsppChromosome::evaluate`: un camino infactible se
+    // penaliza proporcionalmente a cuánto excede los recursos en vez de
+    // con una constante plana, salvo que ni relajando los límites haya
+    // camino hasta el sumidero.
+    fn evaluate(&mut self) {
+        let genes = self.genes();
+        self.fitness = match decode_chromosome(&self.context.graph, &genes, &self.context.resource_limits) {
+            Some(result) => 1.0 / result.cost,
+            None => {
+                let violation = relaxed_violation(&self.context.graph, &genes, &self.context.resource_limits)
+                    .unwrap_or(UNREACHABLE_PENALTY);
+                -(self.context.penalty_weight * violation)
+            }
+        };
+    }
+
+    pub fn path(&self) -> Option<(Vec<usize>, f64)> {
+        decode_chromosome(&self.context.graph, &self.genes(), &self.context.resource_limits)
+            .map(|result| (result.path, result.cost))
+    }
+}
+
+impl Generate for PriorityKeyChromosome {
+    type Params = PriorityKeyParams;
+
+    fn generate(params: &Self::Params, rng: &mut impl Rng) -> Self {
+        let num_intermediate = params.context.graph.num_nodes - 2;
+        let keys: Vec<f64> = (0..num_intermediate).map(|_| rng.random::<f64>()).collect();
+
+        let mut chromosome = PriorityKeyChromosome {
+            keys,
+            fitness: 0.0,
+            sigma: params.initial_sigma,
+            sigma_decay: params.sigma_decay,
+            context: Rc::clone(&params.context),
+        };
+        chromosome.evaluate();
+        chromosome
+    }
+}
+
+impl Genome for PriorityKeyChromosome {
+    // Cruce aritmético (blend): cada clave del hijo es una combinación
+    // convexa con coeficiente aleatorio de las claves de los padres.
+    fn crossover(&self, other: &Self, rng: &mut impl Rng) -> Self {
+        let keys = self
+            .keys
+            .iter()
+            .zip(&other.keys)
+            .map(|(a, b)| {
+                let alpha = rng.random::<f64>();
+                alpha * a + (1.0 - alpha) * b
+            })
+            .collect();
+
+        let mut child = PriorityKeyChromosome {
+            keys,
+            fitness: 0.0,
+            sigma: (self.sigma + other.sigma) / 2.0,
+            sigma_decay: self.sigma_decay,
+            context: Rc::clone(&self.context),
+        };
+        child.evaluate();
+        child
+    }
+
+    // Mutación gaussiana: con probabilidad `rate` por gen se le suma
+    // ruido N(0, sigma); `sigma` decae geométricamente en cada llamada
+    // según `sigma_decay`, para que la búsqueda pase de exploración amplia
+    // a ajuste fino alrededor de las mejores soluciones.
+    fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
+        let mut mutated = false;
+        for key in &mut self.keys {
+            if rng.random::<f64>() < rate {
+                *key += sample_standard_normal(rng) * self.sigma;
+                mutated = true;
+            }
+        }
+        self.sigma *= self.sigma_decay;
+
+        if mutated {
+            self.evaluate();
+        }
+    }
+
+    fn fitness(&self) -> f64 {
+        self.fitness
+    }
+}
+
+// Muestra una N(0, 1) mediante la transformación de Box-Muller, para no
+// añadir una dependencia nueva sólo para generar ruido gaussiano.
+fn sample_standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = rng.random::<f64>().max(f64::MIN_POSITIVE);
+    let u2: f64 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
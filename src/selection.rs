@@ -0,0 +1,61 @@
+use rand::Rng;
+
+use crate::genome::Genome;
+
+// Estrategia de selección de padres, configurable en `GaConfig` en vez de
+// quedar cableada a torneo dentro del bucle de generaciones.
+#[derive(Clone)]
+pub enum Selection {
+    Tournament { size: usize },
+    RouletteWheel,
+}
+
+pub fn select<G: Genome>(population: &[G], strategy: &Selection, rng: &mut impl Rng) -> G {
+    match *strategy {
+        Selection::Tournament { size } => tournament_selection(population, size, rng),
+        Selection::RouletteWheel => roulette_wheel_selection(population, rng),
+    }
+}
+
+// Selección por torneo: elige el mejor de `tournament_size` individuos
+// muestreados al azar de la población.
+pub fn tournament_selection<G: Genome>(
+    population: &[G],
+    tournament_size: usize,
+    rng: &mut impl Rng,
+) -> G {
+    let mut best = &population[rng.random_range(0..population.len())];
+
+    for _ in 1..tournament_size {
+        let competitor = &population[rng.random_range(0..population.len())];
+        if competitor.fitness() > best.fitness() {
+            best = competitor;
+        }
+    }
+
+    best.clone()
+}
+
+// Selección proporcional al fitness (ruleta): construimos la distribución
+// acumulada sobre fitness no negativo y buscamos en binario el primer
+// prefijo que supera una muestra uniforme en [0, total). Si todos los
+// fitness son 0 (o negativos, como puede pasar con la penalización de
+// infactibilidad) no hay gradiente que seguir, así que recurrimos a
+// selección uniforme para no dividir por cero.
+fn roulette_wheel_selection<G: Genome>(population: &[G], rng: &mut impl Rng) -> G {
+    let mut cumulative = Vec::with_capacity(population.len());
+    let mut running = 0.0;
+    for individual in population {
+        running += individual.fitness().max(0.0);
+        cumulative.push(running);
+    }
+    let total_fitness = running;
+
+    if total_fitness <= 0.0 {
+        return population[rng.random_range(0..population.len())].clone();
+    }
+
+    let target = rng.random::<f64>() * total_fitness;
+    let idx = cumulative.partition_point(|&c| c <= target);
+    population[idx.min(population.len() - 1)].clone()
+}